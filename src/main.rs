@@ -1,20 +1,212 @@
 use anyhow::{bail, Context, Ok};
 use chrono::format::{DelayedFormat, StrftimeItems};
-use chrono::{Local, NaiveDateTime, TimeDelta, Timelike};
-use clap::{ArgAction, Parser, Subcommand};
+use chrono::{Datelike, Local, NaiveDateTime, TimeDelta, Timelike, Weekday};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
 use homedir::my_home;
 use polodb_core::bson::*;
 use polodb_core::{Collection, Database};
 use serde::{Deserialize, Serialize};
 use std::ops::Sub;
 use std::str::FromStr;
+use std::sync::OnceLock;
 use std::{fmt::Display, fs, path::PathBuf};
 
+/// User-overridable behavior normally baked into the binary, loaded once from
+/// `~/.quote-it/config.toml` in `main`. Any field left out of the file keeps its default.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    /// Format used to display stored dates, e.g. in `quote-it list` output
+    pub display_date_format: String,
+    /// Format `parse_date` expects before falling back to relative expressions
+    pub input_date_format: String,
+    /// Author applied by `add_quote` when `--author` is omitted
+    pub default_author: Option<String>,
+    /// Record and display dates in UTC instead of local time, overridden by `--utc`
+    pub utc: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            display_date_format: "%m-%d-%Y".to_string(),
+            input_date_format: "%m-%d-%Y".to_string(),
+            default_author: None,
+            utc: false,
+        }
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Returns the config loaded by `main`, or today's defaults if it hasn't been set yet
+/// (e.g. in tests that call parsing/formatting helpers directly).
+fn config() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+static USE_UTC: OnceLock<bool> = OnceLock::new();
+
+/// Whether dates should be recorded/displayed in UTC, per `--utc` or the config file.
+fn use_utc() -> bool {
+    *USE_UTC.get_or_init(|| config().utc)
+}
+
+/// Returns `~/.quote-it`, creating it if it doesn't already exist.
+fn quote_it_dir() -> anyhow::Result<PathBuf> {
+    let mut dir = my_home()?.unwrap();
+    dir.push(".quote-it");
+
+    if !dir.exists() {
+        fs::create_dir(&dir).context("Failed to create quotes directory")?;
+    }
+
+    Ok(dir)
+}
+
+fn load_config() -> anyhow::Result<Config> {
+    let mut file_path = quote_it_dir()?;
+    file_path.push("config.toml");
+
+    if !file_path.exists() {
+        return Ok(Config::default());
+    }
+
+    let contents = fs::read_to_string(&file_path).context("Failed to read config.toml")?;
+    toml::from_str(&contents).context("Failed to parse config.toml")
+}
+
+/// Parses `mm-dd-yyyy` (or the configured `input_date_format`), falling back to
+/// human/relative expressions like `today`, `yesterday`, `tomorrow`, `3 days ago`,
+/// `2 weeks ago`, or a weekday name.
 fn parse_date(arg: &str) -> anyhow::Result<DateTime> {
-    let naive_date_time =
-        NaiveDateTime::parse_from_str(&format!("{}T0:00:00", arg), "%m-%d-%YT%H:%M:%S")
-            .context("Dates must be formatted with `mm-dd-yyyy`")?;
-    Ok(DateTime::from_chrono(naive_date_time.and_utc()))
+    if let Result::Ok(naive_date_time) = parse_fixed_date(arg, &config().input_date_format) {
+        return Ok(DateTime::from_chrono(naive_in_chosen_zone_to_utc(
+            naive_date_time,
+            use_utc(),
+        )?));
+    }
+
+    parse_relative_date(arg).with_context(|| {
+        format!(
+            "Dates must be formatted with `{}`, or a relative expression like `today`, `yesterday`, `3 days ago`, or a weekday name",
+            config().input_date_format
+        )
+    })
+}
+
+/// Parses `arg` as a calendar date at midnight using `input_format` (normally
+/// `config().input_date_format`), pulled out of `parse_date` so the configured input format's
+/// effect can be tested without touching the process-global `Config`.
+fn parse_fixed_date(arg: &str, input_format: &str) -> chrono::ParseResult<NaiveDateTime> {
+    let format = format!("{}T%H:%M:%S", input_format);
+    NaiveDateTime::parse_from_str(&format!("{}T0:00:00", arg), &format)
+}
+
+/// Interprets `naive` as wall-clock time in the chosen zone (UTC if `use_utc`, else `Local`,
+/// the same zone `to_date_formatted` renders in) and converts it to a real UTC instant.
+fn naive_in_chosen_zone_to_utc(
+    naive: NaiveDateTime,
+    use_utc: bool,
+) -> anyhow::Result<chrono::DateTime<chrono::Utc>> {
+    if use_utc {
+        Ok(naive.and_utc())
+    } else {
+        naive
+            .and_local_timezone(Local)
+            .earliest()
+            .context("Local date/time does not exist (skipped by a clock change)")
+            .map(|local| local.with_timezone(&chrono::Utc))
+    }
+}
+
+fn parse_relative_date(arg: &str) -> anyhow::Result<DateTime> {
+    let normalized = arg.trim().to_lowercase();
+    let use_utc = use_utc();
+    let now = if use_utc {
+        chrono::Utc::now().naive_utc()
+    } else {
+        Local::now().naive_local()
+    };
+
+    let target = if normalized == "today" {
+        now
+    } else if normalized == "yesterday" {
+        now - TimeDelta::days(1)
+    } else if normalized == "tomorrow" {
+        now + TimeDelta::days(1)
+    } else if let Some(rest) = normalized.strip_suffix(" ago") {
+        now - parse_ago_offset(rest)?
+    } else if let Some(weekday) = parse_weekday(&normalized) {
+        let mut date = now;
+        while date.weekday() != weekday {
+            date -= TimeDelta::days(1);
+        }
+        date
+    } else {
+        bail!("Unrecognized date expression `{}`", arg);
+    };
+
+    Ok(DateTime::from_chrono(naive_in_chosen_zone_to_utc(
+        zero_time(target),
+        use_utc,
+    )?))
+}
+
+/// Parses the `N day(s)/week(s)/month(s)` half of an `"N <unit> ago"` expression.
+/// Months are approximated as 30 days, which is fine for the "browse recent quotes" use case.
+fn parse_ago_offset(rest: &str) -> anyhow::Result<TimeDelta> {
+    let mut parts = rest.splitn(2, char::is_whitespace);
+    let amount: i64 = parts
+        .next()
+        .context("Expected a number before the time unit, e.g. `3 days ago`")?
+        .parse()
+        .context("Expected a number before the time unit, e.g. `3 days ago`")?;
+    let unit = parts
+        .next()
+        .context("Expected a time unit, e.g. `3 days ago`")?;
+
+    Ok(match unit {
+        "day" | "days" => TimeDelta::days(amount),
+        "week" | "weeks" => TimeDelta::weeks(amount),
+        "month" | "months" => TimeDelta::days(amount * 30),
+        _ => bail!(
+            "Unrecognized time unit `{}`, expected day(s), week(s), or month(s)",
+            unit
+        ),
+    })
+}
+
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    use chrono::Weekday::*;
+    Some(match s {
+        "monday" => Mon,
+        "tuesday" => Tue,
+        "wednesday" => Wed,
+        "thursday" => Thu,
+        "friday" => Fri,
+        "saturday" => Sat,
+        "sunday" => Sun,
+        _ => return None,
+    })
+}
+
+/// Zeroes out the time-of-day, leaving only the calendar date.
+fn zero_time(naive: NaiveDateTime) -> NaiveDateTime {
+    naive
+        .with_hour(0)
+        .unwrap()
+        .with_minute(0)
+        .unwrap()
+        .with_second(0)
+        .unwrap()
+        .with_nanosecond(0)
+        .unwrap()
+}
+
+/// The start of the next calendar day, used to turn a day boundary into a half-open range.
+fn start_of_next_day(date: &DateTime) -> DateTime {
+    DateTime::from_chrono(date.to_chrono() + TimeDelta::days(1))
 }
 
 #[derive(Parser, Debug, Clone)]
@@ -32,6 +224,9 @@ pub struct CLI {
     /// Add a date
     #[arg(short, long,action=ArgAction::SetTrue)]
     pub date: bool,
+    /// Record and display dates in UTC instead of local time
+    #[arg(long, global = true, action=ArgAction::SetTrue)]
+    pub utc: bool,
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -43,19 +238,51 @@ pub enum Commands {
         /// Lists quotes made by specified author
         #[arg(long, short = 'A')]
         author: Option<String>,
-        /// List quotes before this date (inclusive, format: mm-dd-yyyy)
-        #[arg(long, short, value_parser = parse_date)]
-        before: Option<DateTime>,
-        /// List quotes on this date (format: mm-dd-yyyy)
-        #[arg(long, short, value_parser = parse_date)]
-        on: Option<DateTime>,
-        /// List quotes after this date (inclusive, format: mm-dd-yyyy)
-        #[arg(long, short, value_parser = parse_date)]
-        after: Option<DateTime>,
+        /// List quotes before this date (inclusive, format: mm-dd-yyyy, or relative e.g. "2 weeks ago")
+        ///
+        /// Parsed after `CLI::parse()` returns (not as a clap `value_parser`), so that `--utc`
+        /// is already known by the time a relative expression like "2 weeks ago" is resolved.
+        #[arg(long, short)]
+        before: Option<String>,
+        /// List quotes on this date (format: mm-dd-yyyy, or relative e.g. "yesterday")
+        #[arg(long, short)]
+        on: Option<String>,
+        /// List quotes after this date (inclusive, format: mm-dd-yyyy, or relative e.g. "2 weeks ago")
+        #[arg(long, short)]
+        after: Option<String>,
+    },
+    /// Exports all stored quotes to a file
+    Export {
+        /// File to write the exported quotes to
+        path: PathBuf,
+        /// Export format
+        #[arg(long, value_enum, default_value = "json")]
+        format: ExportFormat,
     },
+    /// Imports quotes from a file previously written by `export`
+    Import {
+        /// File to read quotes from
+        path: PathBuf,
+        /// Format the file is encoded in
+        #[arg(long, value_enum, default_value = "json")]
+        format: ExportFormat,
+    },
+}
+
+/// On-disk encoding for `export`/`import`.
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum ExportFormat {
+    /// A single JSON array of quotes
+    Json,
+    /// Newline-delimited JSON, one quote per line
+    Jsonl,
+    /// `quote,author,date` with RFC 3339 dates
+    Csv,
+    /// The human-readable format printed by `quote-it list`
+    Text,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+#[derive(Debug, Default, PartialEq, Deserialize, Serialize)]
 pub struct Quote {
     quote: String,
     author: Option<String>,
@@ -69,10 +296,31 @@ pub trait ToChronoDateFormatted {
 
 impl ToChronoDateFormatted for DateTime {
     fn to_date_formatted(&self) -> DelayedFormat<StrftimeItems> {
-        self.to_chrono().format("%m-%d-%Y")
+        format_in_zone(self, &config().display_date_format, use_utc())
+    }
+}
+
+/// Formats `date` with `display_format` (normally `config().display_date_format`), in UTC or
+/// local time per `use_utc`. Pulled out of `to_date_formatted` so the configured display
+/// format's effect can be tested without touching the process-global `Config`.
+fn format_in_zone<'a>(
+    date: &DateTime,
+    display_format: &'a str,
+    use_utc: bool,
+) -> DelayedFormat<StrftimeItems<'a>> {
+    if use_utc {
+        date.to_chrono().format(display_format)
+    } else {
+        date.to_chrono().with_timezone(&Local).format(display_format)
     }
 }
 
+/// Marks the end of a quote block in the human-readable `Display`/`FromStr`/`parse_many`
+/// format. Note: `parse_many` splits on every occurrence of this run of hyphens, so a quote or
+/// author whose text contains it anywhere (e.g. a pasted markdown rule) is cut apart there
+/// and does not round-trip.
+const QUOTE_SEPARATOR: &str = "------------";
+
 impl Display for Quote {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut quote_string = String::from_str(&format!("{:#?}", self.quote)).unwrap();
@@ -83,20 +331,107 @@ impl Display for Quote {
         if let Some(quote_timestamp) = self.date.as_ref() {
             quote_string.push_str(&format!(" on {}", quote_timestamp.to_date_formatted()))
         };
-        quote_string.push_str("\n------------");
+        quote_string.push_str(&format!("\n{}", QUOTE_SEPARATOR));
 
         writeln!(f, "{}", quote_string)
     }
 }
 
+impl FromStr for Quote {
+    type Err = anyhow::Error;
+
+    /// Inverse of `Display`: parses a `"quote text"\n  - Author on mm-dd-yyyy\n------------` block.
+    fn from_str(block: &str) -> anyhow::Result<Self> {
+        let block = block.trim();
+        if block.is_empty() {
+            bail!("Cannot parse an empty quote block");
+        }
+
+        let mut lines = block.lines();
+
+        let quote_line = lines.next().context("Missing quote text")?;
+
+        // An author-less dated quote gets its `" on <date>"` appended directly onto the quote
+        // line by `Display` (no author line to carry it), so the JSON string doesn't span the
+        // whole line. Decode only as much of it as is valid JSON and treat anything left over
+        // as that trailing `" on <date>"`.
+        let mut quote_json = serde_json::Deserializer::from_str(quote_line).into_iter::<String>();
+        let quote: String = quote_json
+            .next()
+            .context("Missing quote text")?
+            .context(
+                "Quote text must be a double-quoted string, as printed by `Display` (e.g. \"my quote\")",
+            )?;
+        let trailing = quote_line[quote_json.byte_offset()..].trim();
+
+        let mut author = None;
+        let mut date = if let Some(date_str) = trailing.strip_prefix("on ") {
+            Some(parse_date(date_str.trim())?)
+        } else if !trailing.is_empty() {
+            bail!("Unexpected trailing text after quote: `{}`", trailing);
+        } else {
+            None
+        };
+
+        for line in lines {
+            let line = line.trim();
+            // `Display` terminates every block with its own separator line, so `from_str`
+            // called directly on one block (rather than through `parse_many`, which strips
+            // separators first) still has to see and skip it here.
+            if line.is_empty() || line == QUOTE_SEPARATOR {
+                continue;
+            }
+
+            let rest = line
+                .strip_prefix("- ")
+                .context("Expected a `  - Author[ on <date>]` line")?;
+
+            // Split on the *last* " on ", since `Display` appends it after the full author
+            // string, and only treat it as the date separator if what follows actually parses
+            // as a date — an author can legitimately contain " on " (e.g. "Someone on
+            // Vacation"), and in that case nothing after the last occurrence will parse.
+            match rest
+                .rsplit_once(" on ")
+                .and_then(|(author_part, date_part)| {
+                    parse_date(date_part.trim())
+                        .ok()
+                        .map(|parsed| (author_part, parsed))
+                }) {
+                Some((author_part, parsed_date)) => {
+                    author = Some(author_part.to_string());
+                    date = Some(parsed_date);
+                }
+                None => author = Some(rest.to_string()),
+            }
+        }
+
+        Ok(Quote {
+            quote,
+            author,
+            date,
+        })
+    }
+}
+
 impl Quote {
-    fn list_quotes(
+    /// Parses the output of multiple `Display`ed quotes, splitting on the `QUOTE_SEPARATOR`.
+    fn parse_many(text: &str) -> anyhow::Result<Vec<Quote>> {
+        text.split(QUOTE_SEPARATOR)
+            .map(str::trim)
+            .filter(|block| !block.is_empty())
+            .map(Quote::from_str)
+            .collect()
+    }
+
+    /// Runs the `author`/`on`/`before`/`after` filters against `quotes` and returns the matches.
+    /// Pulled out of `list_quotes` so the query logic can be exercised directly in tests.
+    fn find_quotes(
         quotes: &Collection<Self>,
         author: Option<String>,
         on: Option<DateTime>,
         before: Option<DateTime>,
         after: Option<DateTime>,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<Vec<Quote>> {
         // If statements go wild here, though code is very readable
         if on.is_some() && (before.is_some() || after.is_some()) {
             bail!("Cannot specify `on` date if using `before` or `after` filters");
@@ -116,39 +451,46 @@ impl Quote {
         let mut doc = bson::Document::new();
 
         if author.is_some() {
-            doc.insert("author", &author);
+            doc.insert("author", author);
         }
 
-        if before.is_some() {
-            doc.insert(
-                "date",
-                doc! {
-                    "$lte": &before
-                },
-            );
-        }
+        // `before`/`on`/`after` are zeroed to the start of a day by `parse_date`, so matching
+        // a whole day means a half-open range: [start_of_day, start_of_next_day).
+        let on_end = on.map(|on| start_of_next_day(&on));
+        let before_end = before.map(|before| start_of_next_day(&before));
+
+        let mut date_filter = bson::Document::new();
 
         if on.is_some() {
-            doc.insert(
-                "date",
-                doc! {
-                    "$eq": &on
-                },
-            );
+            date_filter.insert("$gte", on);
+            date_filter.insert("$lt", on_end);
         }
 
         if after.is_some() {
-            doc.insert(
-                "date",
-                doc! {
-                    "$gte": &after
-                },
-            );
+            date_filter.insert("$gte", after);
         }
 
-        let found_quotes = quotes
+        if before.is_some() {
+            date_filter.insert("$lt", before_end);
+        }
+
+        if !date_filter.is_empty() {
+            doc.insert("date", date_filter);
+        }
+
+        Ok(quotes
             .find(if !doc.is_empty() { Some(doc) } else { None })?
-            .collect::<polodb_core::Result<Vec<Quote>>>()?;
+            .collect::<polodb_core::Result<Vec<Quote>>>()?)
+    }
+
+    fn list_quotes(
+        quotes: &Collection<Self>,
+        author: Option<String>,
+        on: Option<DateTime>,
+        before: Option<DateTime>,
+        after: Option<DateTime>,
+    ) -> anyhow::Result<()> {
+        let found_quotes = Self::find_quotes(quotes, author.clone(), on, before, after)?;
 
         if found_quotes.len() == 0 {
             let mut message = String::from("No quotes found");
@@ -192,40 +534,216 @@ impl Quote {
         quote: String,
         author: Option<String>,
         date: bool,
+        config: &Config,
     ) -> anyhow::Result<()> {
         let mut new_quote = Self::default();
 
         new_quote.quote = quote;
-        new_quote.author = author;
+        new_quote.author = author.or_else(|| config.default_author.clone());
 
         if date {
             // Yes this solution is jank, so is everything in this repo to do with dates/time
-            let local = Local::now().naive_local();
-            let date_zeroed_time = local
-                .with_hour(0)
-                .unwrap()
-                .with_minute(0)
-                .unwrap()
-                .with_second(0)
-                .unwrap()
-                .with_nanosecond(0)
-                .unwrap()
-                .and_utc();
-
-            let bson_date = DateTime::from_chrono(date_zeroed_time);
-            new_quote.date = Some(bson_date);
+            let now_utc = if use_utc() {
+                chrono::Utc::now()
+            } else {
+                Local::now().with_timezone(&chrono::Utc)
+            };
+            new_quote.date = Some(DateTime::from_chrono(now_utc));
         }
         quotes.insert_one(new_quote)?;
 
         Ok(())
     }
+
+    fn export_quotes(
+        quotes: &Collection<Self>,
+        path: &std::path::Path,
+        format: ExportFormat,
+    ) -> anyhow::Result<()> {
+        let found_quotes = quotes
+            .find(None)?
+            .collect::<polodb_core::Result<Vec<Quote>>>()?;
+
+        let contents = match format {
+            ExportFormat::Json => serde_json::to_string_pretty(&found_quotes)
+                .context("Failed to serialize quotes to JSON")?,
+            ExportFormat::Jsonl => found_quotes
+                .iter()
+                .map(serde_json::to_string)
+                .collect::<serde_json::Result<Vec<String>>>()
+                .context("Failed to serialize quotes to JSON")?
+                .join("\n"),
+            ExportFormat::Csv => found_quotes
+                .iter()
+                .map(quote_to_csv_row)
+                .collect::<Vec<String>>()
+                .join("\n"),
+            ExportFormat::Text => found_quotes.iter().map(Quote::to_string).collect(),
+        };
+
+        fs::write(path, contents).context("Failed to write export file")?;
+
+        println!(
+            "Exported {} quote(s) to {}",
+            found_quotes.len(),
+            path.display()
+        );
+
+        Ok(())
+    }
+
+    fn import_quotes(
+        quotes: &Collection<Self>,
+        path: &std::path::Path,
+        format: ExportFormat,
+    ) -> anyhow::Result<()> {
+        let contents = fs::read_to_string(path).context("Failed to read import file")?;
+
+        let imported = match format {
+            ExportFormat::Json => {
+                serde_json::from_str::<Vec<Quote>>(&contents).context("Failed to parse JSON")?
+            }
+            ExportFormat::Jsonl => contents
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<serde_json::Result<Vec<Quote>>>()
+                .context("Failed to parse newline-delimited JSON")?,
+            ExportFormat::Csv => parse_csv_rows(&contents)
+                .into_iter()
+                .filter(|fields| !(fields.len() == 1 && fields[0].is_empty()))
+                .map(|fields| csv_fields_to_quote(&fields))
+                .collect::<anyhow::Result<Vec<Quote>>>()
+                .context("Failed to parse CSV")?,
+            ExportFormat::Text => {
+                Quote::parse_many(&contents).context("Failed to parse quote text")?
+            }
+        };
+
+        let imported_count = imported.len();
+        for quote in imported {
+            quotes.insert_one(quote)?;
+        }
+
+        println!(
+            "Imported {} quote(s) from {}",
+            imported_count,
+            path.display()
+        );
+
+        Ok(())
+    }
+}
+
+/// Escapes a CSV field, quoting it if it contains a comma, quote, or newline.
+fn csv_escape(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn quote_to_csv_row(quote: &Quote) -> String {
+    let date = quote
+        .date
+        .as_ref()
+        .map(|d| d.to_chrono().to_rfc3339())
+        .unwrap_or_default();
+
+    format!(
+        "{},{},{}",
+        csv_escape(&quote.quote),
+        csv_escape(quote.author.as_deref().unwrap_or("")),
+        date
+    )
+}
+
+/// Parses a whole RFC4180 document into rows of fields, honoring `"`-quoted fields with `""`
+/// escapes and embedded `\n`s (quote-state is tracked across the entire document, not reset
+/// per line, since `csv_escape` may emit a literal newline inside a quoted field).
+fn parse_csv_rows(text: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    current.push('"');
+                    chars.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                current.push(c);
+            }
+        } else {
+            match c {
+                '"' => in_quotes = true,
+                ',' => fields.push(std::mem::take(&mut current)),
+                '\n' => {
+                    fields.push(std::mem::take(&mut current));
+                    rows.push(std::mem::take(&mut fields));
+                }
+                '\r' => {}
+                _ => current.push(c),
+            }
+        }
+    }
+    if !current.is_empty() || !fields.is_empty() {
+        fields.push(current);
+        rows.push(fields);
+    }
+
+    rows
+}
+
+fn csv_fields_to_quote(fields: &[String]) -> anyhow::Result<Quote> {
+    if fields.len() != 3 {
+        bail!(
+            "Expected 3 CSV fields (quote,author,date), found {}",
+            fields.len()
+        );
+    }
+
+    let date = if fields[2].is_empty() {
+        None
+    } else {
+        Some(DateTime::from_chrono(
+            chrono::DateTime::parse_from_rfc3339(&fields[2])
+                .context("Failed to parse CSV date as RFC 3339")?
+                .with_timezone(&chrono::Utc),
+        ))
+    };
+
+    Ok(Quote {
+        quote: fields[0].clone(),
+        author: if fields[1].is_empty() {
+            None
+        } else {
+            Some(fields[1].clone())
+        },
+        date,
+    })
 }
+
 fn main() -> anyhow::Result<()> {
     // println!("Hello Quote it!");
-    // Get CLI args
+    let loaded_config = load_config()?;
+    CONFIG.set(loaded_config).ok();
 
+    // Get CLI args
     let args = CLI::parse();
 
+    // `USE_UTC` must be resolved from the parsed `args.utc` before any relative
+    // `--before`/`--on`/`--after` string (e.g. "2 weeks ago") gets resolved below, since that
+    // resolution depends on the chosen zone.
+    USE_UTC.set(args.utc || config().utc).ok();
+
     let db = Database::open_file(get_file_path()?).context("Database file search failed")?;
 
     let quotes: Collection<Quote> = db.collection("quotes");
@@ -237,26 +755,26 @@ fn main() -> anyhow::Result<()> {
                 before,
                 on,
                 after,
-            } => Quote::list_quotes(&quotes, author, on, before, after)?,
+            } => {
+                let before = before.as_deref().map(parse_date).transpose()?;
+                let on = on.as_deref().map(parse_date).transpose()?;
+                let after = after.as_deref().map(parse_date).transpose()?;
+                Quote::list_quotes(&quotes, author, on, before, after)?
+            }
+            Commands::Export { path, format } => Quote::export_quotes(&quotes, &path, format)?,
+            Commands::Import { path, format } => Quote::import_quotes(&quotes, &path, format)?,
         };
     };
 
     if let Some(quote) = args.quote {
-        Quote::add_quote(&quotes, quote, args.author, args.date)?;
+        Quote::add_quote(&quotes, quote, args.author, args.date, config())?;
     }
 
     Ok(())
 }
 
 fn get_file_path() -> anyhow::Result<PathBuf> {
-    let mut file_path = my_home()?.unwrap();
-
-    file_path.push(".quote-it");
-
-    if !file_path.exists() {
-        fs::create_dir(&file_path).context("Failed to create quotes directory")?;
-    }
-
+    let mut file_path = quote_it_dir()?;
     file_path.push("quotes.db");
 
     if !file_path.exists() {
@@ -265,3 +783,418 @@ fn get_file_path() -> anyhow::Result<PathBuf> {
 
     Ok(file_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn fixed_date() -> DateTime {
+        DateTime::from_chrono(chrono::Utc.with_ymd_and_hms(2026, 7, 29, 0, 0, 0).unwrap())
+    }
+
+    /// Midnight of the same calendar day in the local zone, which is the zone `Display` renders
+    /// and `parse_date` reads dates in while `--utc` is off, so it survives a text round-trip.
+    fn local_midnight_date() -> DateTime {
+        DateTime::from_chrono(
+            Local
+                .with_ymd_and_hms(2026, 7, 29, 0, 0, 0)
+                .earliest()
+                .unwrap()
+                .with_timezone(&chrono::Utc),
+        )
+    }
+
+    #[test]
+    fn config_parses_overridden_date_formats_and_default_author() {
+        let config: Config = toml::from_str(
+            r#"
+            display_date_format = "%d.%m.%Y"
+            input_date_format = "%d.%m.%Y"
+            default_author = "Ada Lovelace"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.display_date_format, "%d.%m.%Y");
+        assert_eq!(config.input_date_format, "%d.%m.%Y");
+        assert_eq!(config.default_author, Some("Ada Lovelace".to_string()));
+    }
+
+    #[test]
+    fn config_falls_back_to_defaults_for_fields_omitted_from_the_file() {
+        let config: Config = toml::from_str(r#"default_author = "Ada Lovelace""#).unwrap();
+
+        assert_eq!(
+            config.display_date_format,
+            Config::default().display_date_format
+        );
+        assert_eq!(
+            config.input_date_format,
+            Config::default().input_date_format
+        );
+        assert_eq!(config.default_author, Some("Ada Lovelace".to_string()));
+    }
+
+    #[test]
+    fn parse_fixed_date_honors_a_non_default_input_format() {
+        let parsed = parse_fixed_date("29.07.2026", "%d.%m.%Y").unwrap();
+        assert_eq!(parsed.format("%Y-%m-%d").to_string(), "2026-07-29");
+    }
+
+    #[test]
+    fn format_in_zone_honors_a_non_default_display_format() {
+        assert_eq!(
+            format_in_zone(&fixed_date(), "%d.%m.%Y", true).to_string(),
+            "29.07.2026"
+        );
+    }
+
+    #[test]
+    fn add_quote_falls_back_to_configs_default_author_when_not_specified() {
+        let (_db, quotes) = memory_quotes();
+        let config = Config {
+            default_author: Some("Default Author".to_string()),
+            ..Config::default()
+        };
+
+        Quote::add_quote(
+            &quotes,
+            "Quote with no --author flag".to_string(),
+            None,
+            false,
+            &config,
+        )
+        .unwrap();
+
+        let found = quotes
+            .find(None)
+            .unwrap()
+            .collect::<polodb_core::Result<Vec<Quote>>>()
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].author, Some("Default Author".to_string()));
+    }
+
+    #[test]
+    fn round_trips_a_bare_quote() {
+        let quote = Quote {
+            quote: "Hello, World!".to_string(),
+            author: None,
+            date: None,
+        };
+        let parsed: Quote = quote.to_string().parse().unwrap();
+        assert_eq!(quote, parsed);
+    }
+
+    #[test]
+    fn round_trips_a_quote_with_author_and_no_date() {
+        let quote = Quote {
+            quote: "Simplicity is a prerequisite for reliability.".to_string(),
+            author: Some("Edsger Dijkstra".to_string()),
+            date: None,
+        };
+        let parsed: Quote = quote.to_string().parse().unwrap();
+        assert_eq!(quote, parsed);
+    }
+
+    #[test]
+    fn round_trips_a_quote_with_author_and_date() {
+        let quote = Quote {
+            quote: "Talk is cheap. Show me the code.".to_string(),
+            author: Some("Linus Torvalds".to_string()),
+            date: Some(local_midnight_date()),
+        };
+        let parsed: Quote = quote.to_string().parse().unwrap();
+        assert_eq!(quote, parsed);
+    }
+
+    #[test]
+    fn round_trips_a_dated_quote_with_no_author() {
+        // Regression test: `Display` appends `" on <date>"` onto the quote line itself when
+        // there's no author line to carry it, which used to break `FromStr`.
+        let quote = Quote {
+            quote: "Premature optimization is the root of all evil.".to_string(),
+            author: None,
+            date: Some(local_midnight_date()),
+        };
+        let parsed: Quote = quote.to_string().parse().unwrap();
+        assert_eq!(quote, parsed);
+    }
+
+    #[test]
+    fn round_trips_a_quote_whose_author_contains_the_word_on() {
+        // Regression test: splitting on the *first* " on " would mistake this author for a
+        // date separator and fail to parse `Vacation` as a date.
+        let quote = Quote {
+            quote: "Out of office.".to_string(),
+            author: Some("Someone on Vacation".to_string()),
+            date: Some(local_midnight_date()),
+        };
+        let parsed: Quote = quote.to_string().parse().unwrap();
+        assert_eq!(quote, parsed);
+    }
+
+    #[test]
+    fn parse_many_splits_on_the_separator() {
+        let quotes = vec![
+            Quote {
+                quote: "First".to_string(),
+                author: None,
+                date: None,
+            },
+            Quote {
+                quote: "Second".to_string(),
+                author: Some("Author".to_string()),
+                date: Some(local_midnight_date()),
+            },
+        ];
+        let text: String = quotes.iter().map(Quote::to_string).collect();
+
+        assert_eq!(Quote::parse_many(&text).unwrap(), quotes);
+    }
+
+    #[test]
+    fn parse_ago_offset_understands_days_weeks_and_months() {
+        assert_eq!(parse_ago_offset("3 days").unwrap(), TimeDelta::days(3));
+        assert_eq!(parse_ago_offset("1 day").unwrap(), TimeDelta::days(1));
+        assert_eq!(parse_ago_offset("2 weeks").unwrap(), TimeDelta::weeks(2));
+        assert_eq!(parse_ago_offset("1 month").unwrap(), TimeDelta::days(30));
+    }
+
+    #[test]
+    fn parse_ago_offset_rejects_unknown_units() {
+        assert!(parse_ago_offset("3 fortnights").is_err());
+        assert!(parse_ago_offset("three days").is_err());
+    }
+
+    #[test]
+    fn parse_weekday_matches_valid_names_only() {
+        assert_eq!(parse_weekday("monday"), Some(Weekday::Mon));
+        assert_eq!(parse_weekday("sunday"), Some(Weekday::Sun));
+        assert_eq!(parse_weekday("notaday"), None);
+    }
+
+    #[test]
+    fn parse_relative_date_resolves_weekday_names_to_that_weekday() {
+        for name in ["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"]
+        {
+            let weekday = parse_weekday(name).unwrap();
+            let resolved = parse_relative_date(name).unwrap();
+            assert_eq!(resolved.to_chrono().with_timezone(&Local).weekday(), weekday);
+        }
+    }
+
+    #[test]
+    fn parse_relative_date_yesterday_is_one_day_before_today() {
+        let today = parse_relative_date("today").unwrap().to_chrono();
+        let yesterday = parse_relative_date("yesterday").unwrap().to_chrono();
+        assert_eq!(today - yesterday, TimeDelta::days(1));
+    }
+
+    #[test]
+    fn naive_in_chosen_zone_to_utc_treats_naive_as_utc_when_use_utc_is_true() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2026, 7, 29)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let utc = naive_in_chosen_zone_to_utc(naive, true).unwrap();
+        assert_eq!(utc, naive.and_utc());
+    }
+
+    #[test]
+    fn naive_in_chosen_zone_to_utc_treats_naive_as_local_when_use_utc_is_false() {
+        let naive = chrono::NaiveDate::from_ymd_opt(2026, 7, 29)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        let utc = naive_in_chosen_zone_to_utc(naive, false).unwrap();
+        let expected = naive
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(utc, expected);
+    }
+
+    #[test]
+    fn csv_escape_quotes_fields_with_commas_quotes_or_newlines() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a, b"), "\"a, b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_escape("line1\nline2"), "\"line1\nline2\"");
+    }
+
+    #[test]
+    fn parse_csv_rows_splits_plain_rows_on_commas_and_newlines() {
+        let rows = parse_csv_rows("a,b,c\nd,e,f");
+        assert_eq!(
+            rows,
+            vec![
+                vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                vec!["d".to_string(), "e".to_string(), "f".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_csv_rows_keeps_embedded_commas_and_newlines_inside_quoted_fields() {
+        let rows = parse_csv_rows("\"a, b\nstill one field\",c\n");
+        assert_eq!(
+            rows,
+            vec![vec!["a, b\nstill one field".to_string(), "c".to_string()]]
+        );
+    }
+
+    #[test]
+    fn parse_csv_rows_unescapes_doubled_quotes() {
+        let rows = parse_csv_rows("\"say \"\"hi\"\"\",b\n");
+        assert_eq!(rows, vec![vec!["say \"hi\"".to_string(), "b".to_string()]]);
+    }
+
+    #[test]
+    fn csv_fields_to_quote_round_trips_through_quote_to_csv_row() {
+        let quote = Quote {
+            quote: "Simplicity, the ultimate sophistication.".to_string(),
+            author: Some("Leonardo da Vinci".to_string()),
+            date: Some(fixed_date()),
+        };
+        let row = quote_to_csv_row(&quote);
+        let fields = parse_csv_rows(&row).into_iter().next().unwrap();
+        assert_eq!(csv_fields_to_quote(&fields).unwrap(), quote);
+    }
+
+    #[test]
+    fn csv_fields_to_quote_treats_empty_author_and_date_as_none() {
+        let fields = vec!["just a quote".to_string(), String::new(), String::new()];
+        let quote = csv_fields_to_quote(&fields).unwrap();
+        assert_eq!(quote.author, None);
+        assert_eq!(quote.date, None);
+    }
+
+    #[test]
+    fn csv_fields_to_quote_rejects_the_wrong_number_of_fields() {
+        let fields = vec!["only one field".to_string()];
+        assert!(csv_fields_to_quote(&fields).is_err());
+    }
+
+    /// `Collection` only holds a `Weak` handle to its `Database`, so the `Database` itself has
+    /// to stay alive (bound to a variable, not dropped as a temporary) for as long as the
+    /// returned `Collection` is used.
+    fn memory_quotes() -> (Database, Collection<Quote>) {
+        let db = Database::open_memory().unwrap();
+        let quotes: Collection<Quote> = db.collection("quotes");
+        (db, quotes)
+    }
+
+    /// A quote stamped mid-afternoon rather than at midnight, matching what `add_quote` stores
+    /// now that it records the full timestamp instead of zeroing the time-of-day.
+    fn afternoon_quote() -> Quote {
+        Quote {
+            quote: "Afternoon thought.".to_string(),
+            author: None,
+            date: Some(DateTime::from_chrono(
+                chrono::Utc.with_ymd_and_hms(2026, 7, 29, 14, 30, 0).unwrap(),
+            )),
+        }
+    }
+
+    #[test]
+    fn find_quotes_on_matches_a_quote_timestamped_any_time_that_day() {
+        let (_db, quotes) = memory_quotes();
+        quotes.insert_one(afternoon_quote()).unwrap();
+
+        let found = Quote::find_quotes(&quotes, None, Some(fixed_date()), None, None).unwrap();
+        assert_eq!(found, vec![afternoon_quote()]);
+    }
+
+    #[test]
+    fn find_quotes_after_matches_the_whole_day_as_a_half_open_range() {
+        let (_db, quotes) = memory_quotes();
+        quotes.insert_one(afternoon_quote()).unwrap();
+
+        let same_day = fixed_date();
+        let next_day = DateTime::from_chrono(same_day.to_chrono() + TimeDelta::days(1));
+
+        assert_eq!(
+            Quote::find_quotes(&quotes, None, None, None, Some(same_day)).unwrap(),
+            vec![afternoon_quote()],
+            "--after the quote's own day should match it, even though it's stamped mid-afternoon"
+        );
+        assert_eq!(
+            Quote::find_quotes(&quotes, None, None, None, Some(next_day)).unwrap(),
+            Vec::<Quote>::new(),
+            "--after the following day should exclude the quote"
+        );
+    }
+
+    #[test]
+    fn find_quotes_before_matches_the_whole_day_as_a_half_open_range() {
+        let (_db, quotes) = memory_quotes();
+        quotes.insert_one(afternoon_quote()).unwrap();
+
+        let same_day = fixed_date();
+        let previous_day = DateTime::from_chrono(same_day.to_chrono() - TimeDelta::days(1));
+
+        assert_eq!(
+            Quote::find_quotes(&quotes, None, None, Some(same_day), None).unwrap(),
+            vec![afternoon_quote()],
+            "--before the quote's own day should match it, even though it's stamped mid-afternoon"
+        );
+        assert_eq!(
+            Quote::find_quotes(&quotes, None, None, Some(previous_day), None).unwrap(),
+            Vec::<Quote>::new(),
+            "--before the previous day should exclude the quote"
+        );
+    }
+
+    #[test]
+    fn export_then_import_json_round_trips_all_quotes() {
+        let (_db, quotes) = memory_quotes();
+        let original = Quote {
+            quote: "Stay hungry, stay foolish.".to_string(),
+            author: Some("Steve Jobs".to_string()),
+            date: Some(fixed_date()),
+        };
+        quotes.insert_one(&original).unwrap();
+
+        let path = std::env::temp_dir().join("quote-it-test-export.json");
+        Quote::export_quotes(&quotes, &path, ExportFormat::Json).unwrap();
+
+        let (_imported_db, imported) = memory_quotes();
+        Quote::import_quotes(&imported, &path, ExportFormat::Json).unwrap();
+        fs::remove_file(&path).ok();
+
+        let found = imported
+            .find(None)
+            .unwrap()
+            .collect::<polodb_core::Result<Vec<Quote>>>()
+            .unwrap();
+        assert_eq!(found, vec![original]);
+    }
+
+    #[test]
+    fn export_then_import_csv_round_trips_all_quotes() {
+        let (_db, quotes) = memory_quotes();
+        let original = Quote {
+            quote: "Field, with a comma".to_string(),
+            author: None,
+            date: None,
+        };
+        quotes.insert_one(&original).unwrap();
+
+        let path = std::env::temp_dir().join("quote-it-test-export.csv");
+        Quote::export_quotes(&quotes, &path, ExportFormat::Csv).unwrap();
+
+        let (_imported_db, imported) = memory_quotes();
+        Quote::import_quotes(&imported, &path, ExportFormat::Csv).unwrap();
+        fs::remove_file(&path).ok();
+
+        let found = imported
+            .find(None)
+            .unwrap()
+            .collect::<polodb_core::Result<Vec<Quote>>>()
+            .unwrap();
+        assert_eq!(found, vec![original]);
+    }
+}